@@ -331,7 +331,7 @@ namespace HackerOSSteam {
             sidebar.append (btn_update);
 
             btn_stop = new ActionButton ("Stop", "media-playback-stop-symbolic", "btn-warning");
-            btn_stop.clicked.connect (() => run_command ({"kill"}));
+            btn_stop.clicked.connect (() => run_command ({"stop"}));
             sidebar.append (btn_stop);
 
             btn_remove = new ActionButton ("Remove", "user-trash-symbolic", "btn-danger");
@@ -111,10 +111,11 @@ var menuItems = []menuItem{
 	{section: "CONTAINER", icon: "+", label: "Create Container", cmd: []string{"create"}},
 	{icon: "⚙", label: "Setup / Repair Steam", cmd: []string{"setup"}},
 	{icon: "↑", label: "Update Container", cmd: []string{"update"}},
-	{icon: "■", label: "Stop Container", cmd: []string{"kill"}},
+	{icon: "■", label: "Stop Container", cmd: []string{"stop"}},
 	{icon: "✕", label: "Remove Container", cmd: []string{"--force", "remove"}, confirm: true},
 
 	{section: "INFO", icon: "i", label: "Container Status", cmd: []string{"status"}},
+	{icon: "◷", label: "Session Timeline", cmd: []string{"status", "--history"}},
 	{icon: "≡", label: "List All Containers", cmd: []string{"list"}},
 }
 